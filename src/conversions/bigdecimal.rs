@@ -53,20 +53,47 @@ use crate::exceptions::PyValueError;
 use crate::sync::GILOnceCell;
 use crate::types::any::PyAnyMethods;
 use crate::types::string::PyStringMethods;
-use crate::types::PyType;
+use crate::types::{PyModule, PyType};
 use crate::{Bound, FromPyObject, IntoPy, Py, PyAny, PyObject, PyResult, Python, ToPyObject};
+use bigdecimal::num_bigint::{BigInt, BigUint, Sign};
 use bigdecimal::BigDecimal;
 use std::str::FromStr;
 
 impl FromPyObject<'_> for BigDecimal {
     fn extract_bound(obj: &Bound<'_, PyAny>) -> PyResult<Self> {
-        // use the string representation to not be lossy
         if let Ok(val) = obj.extract() {
-            Ok(<BigDecimal as From<i64>>::from(val))
-        } else {
-            BigDecimal::from_str(&obj.str()?.to_cow()?)
-                .map_err(|e| PyValueError::new_err(e.to_string()))
+            return Ok(<BigDecimal as From<i64>>::from(val));
+        }
+
+        // `as_tuple()` is specific to `decimal.Decimal` -- anything else (an arbitrary-
+        // precision int, a str, a float, ...) doesn't have it, so fall back to the old
+        // stringify-and-parse path instead of letting the `AttributeError` propagate
+        if let Ok(tuple) = obj.call_method0("as_tuple") {
+            // decompose via `Decimal.as_tuple()` instead of the string form, so that the
+            // digits are never stringified or run through the decimal parser: the value is
+            // `(-1)^sign * int(digits) * 10^exponent`
+            let sign: u8 = tuple.getattr("sign")?.extract()?;
+            let digits: Vec<u8> = tuple.getattr("digits")?.extract()?;
+            let exponent = tuple.getattr("exponent")?;
+
+            // NaN, sNaN and Infinity report a string (`'n'`, `'N'`, `'F'`) here instead of an int
+            let exponent: i64 = exponent.extract().map_err(|_| {
+                PyValueError::new_err("BigDecimal does not support NaN or Infinity values")
+            })?;
+
+            let sign = if sign == 1 { Sign::Minus } else { Sign::Plus };
+            let unsigned = digits
+                .into_iter()
+                .fold(BigUint::from(0u32), |acc, digit| acc * 10u32 + digit);
+            let big_int = BigInt::from_biguint(sign, unsigned);
+
+            // bigdecimal's scale is the negated base-10 exponent
+            return Ok(BigDecimal::new(big_int, -exponent));
         }
+
+        // use the string representation to not be lossy
+        BigDecimal::from_str(&obj.str()?.to_cow()?)
+            .map_err(|e| PyValueError::new_err(e.to_string()))
     }
 }
 
@@ -96,6 +123,104 @@ impl IntoPy<PyObject> for BigDecimal {
     }
 }
 
+static DECIMAL_MOD: GILOnceCell<Py<PyModule>> = GILOnceCell::new();
+
+fn get_decimal_module(py: Python<'_>) -> PyResult<&Bound<'_, PyModule>> {
+    DECIMAL_MOD
+        .get_or_try_init(py, || py.import_bound("decimal").map(Bound::unbind))
+        .map(|module| module.bind(py))
+}
+
+/// Extension trait for converting a [`BigDecimal`] into a Python `decimal.Decimal` that honors
+/// the precision and rounding mode of the thread's active context (`decimal.getcontext()`).
+///
+/// The plain [`ToPyObject`] impl above always carries the full, unbounded precision of the
+/// `BigDecimal` across the FFI boundary. This is useful when callers have configured a
+/// `decimal.Context` (e.g. a fixed number of significant digits with `ROUND_HALF_EVEN`) and
+/// want conversions to respect it, matching the rounding semantics Python code would see.
+pub trait BigDecimalContextExt {
+    /// Converts `self` to a `decimal.Decimal`, rounded according to `decimal.getcontext()`.
+    fn to_object_with_context(&self, py: Python<'_>) -> PyResult<PyObject>;
+}
+
+impl BigDecimalContextExt for BigDecimal {
+    fn to_object_with_context(&self, py: Python<'_>) -> PyResult<PyObject> {
+        let context = get_decimal_module(py)?.call_method0("getcontext")?;
+        let rounded = context.call_method1("create_decimal", (self.to_string(),))?;
+        Ok(rounded.to_object(py))
+    }
+}
+
+/// A Python [`decimal.Decimal`](https://docs.python.org/3/library/decimal.html), including the
+/// `NaN`, `sNaN` and `Infinity` special values that [`BigDecimal`] has no representation for.
+///
+/// The [`BigDecimal`] conversions above stay as they are, rejecting those special values, so
+/// this type is purely additive for callers who need to round-trip the full domain that
+/// Python's `decimal` module supports.
+#[derive(Clone, Debug, PartialEq)]
+pub enum PyDecimal {
+    Finite(BigDecimal),
+    NaN,
+    SignalingNaN,
+    Infinity { negative: bool },
+}
+
+impl FromPyObject<'_> for PyDecimal {
+    fn extract_bound(obj: &Bound<'_, PyAny>) -> PyResult<Self> {
+        // mirror BigDecimal's own int fast-path: a plain `int` has no `is_nan`/`is_infinite`
+        // methods, so it is converted directly without probing them
+        if let Ok(val) = obj.extract() {
+            return Ok(PyDecimal::Finite(<BigDecimal as From<i64>>::from(val)));
+        }
+
+        // `is_nan`/`is_infinite` are specific to `decimal.Decimal` -- anything else (a big
+        // int outside i64 range, a str, a float, ...) doesn't have them, so skip straight to
+        // `BigDecimal`'s own extraction, which already falls back to the string form
+        if let Ok(is_nan) = obj.call_method0("is_nan") {
+            if is_nan.extract()? {
+                return if obj.call_method0("is_snan")?.extract()? {
+                    Ok(PyDecimal::SignalingNaN)
+                } else {
+                    Ok(PyDecimal::NaN)
+                };
+            }
+
+            if obj.call_method0("is_infinite")?.extract()? {
+                let sign: u8 = obj.call_method0("as_tuple")?.getattr("sign")?.extract()?;
+                return Ok(PyDecimal::Infinity { negative: sign == 1 });
+            }
+        }
+
+        Ok(PyDecimal::Finite(obj.extract()?))
+    }
+}
+
+fn decimal_from_str(py: Python<'_>, value: &str) -> PyObject {
+    let dec_cls = get_decimal_cls(py).expect("failed to load decimal.Decimal");
+    let ret = dec_cls
+        .call1((value,))
+        .expect("failed to call decimal.Decimal(value)");
+    ret.to_object(py)
+}
+
+impl ToPyObject for PyDecimal {
+    fn to_object(&self, py: Python<'_>) -> PyObject {
+        match self {
+            PyDecimal::Finite(big_decimal) => big_decimal.to_object(py),
+            PyDecimal::NaN => decimal_from_str(py, "NaN"),
+            PyDecimal::SignalingNaN => decimal_from_str(py, "sNaN"),
+            PyDecimal::Infinity { negative: false } => decimal_from_str(py, "Infinity"),
+            PyDecimal::Infinity { negative: true } => decimal_from_str(py, "-Infinity"),
+        }
+    }
+}
+
+impl IntoPy<PyObject> for PyDecimal {
+    fn into_py(self, py: Python<'_>) -> PyObject {
+        self.to_object(py)
+    }
+}
+
 #[cfg(test)]
 mod test_bigdecimal {
     use super::*;
@@ -175,6 +300,39 @@ mod test_bigdecimal {
         }
     }
 
+    #[test]
+    fn test_big_int_beyond_i64() {
+        // a Python int can be arbitrary precision -- this is the whole point of reaching
+        // for `BigDecimal` over `rust_decimal`, so it must not raise `AttributeError` just
+        // because it has no `as_tuple()` like `decimal.Decimal` does
+        Python::with_gil(|py| {
+            let locals = PyDict::new_bound(py);
+            py.run_bound("py_num = 10 ** 30", None, Some(&locals))
+                .unwrap();
+            let py_num = locals.get_item("py_num").unwrap().unwrap();
+            let roundtripped: BigDecimal = py_num.extract().unwrap();
+            let expected = BigDecimal::from_str(&format!("1{}", "0".repeat(30))).unwrap();
+            assert_eq!(roundtripped, expected);
+        })
+    }
+
+    #[test]
+    fn test_str_and_float_inputs() {
+        Python::with_gil(|py| {
+            let locals = PyDict::new_bound(py);
+            py.run_bound("py_str = \"3.14\"\npy_float = 3.5", None, Some(&locals))
+                .unwrap();
+
+            let py_str = locals.get_item("py_str").unwrap().unwrap();
+            let from_str: BigDecimal = py_str.extract().unwrap();
+            assert_eq!(from_str, BigDecimal::from_str("3.14").unwrap());
+
+            let py_float = locals.get_item("py_float").unwrap().unwrap();
+            let from_float: BigDecimal = py_float.extract().unwrap();
+            assert_eq!(from_float, BigDecimal::from_str("3.5").unwrap());
+        })
+    }
+
     #[test]
     fn test_nan() {
         Python::with_gil(|py| {
@@ -206,4 +364,133 @@ mod test_bigdecimal {
             assert!(roundtripped.is_err());
         })
     }
+
+    macro_rules! convert_pydecimal_specials {
+        ($name:ident, $py:literal, $expected:expr) => {
+            #[test]
+            fn $name() {
+                Python::with_gil(|py| {
+                    let locals = PyDict::new_bound(py);
+                    py.run_bound(
+                        &format!("import decimal\npy_dec = decimal.Decimal(\"{}\")", $py),
+                        None,
+                        Some(&locals),
+                    )
+                    .unwrap();
+                    let py_dec = locals.get_item("py_dec").unwrap().unwrap();
+                    let roundtripped: PyDecimal = py_dec.extract().unwrap();
+                    assert_eq!(roundtripped, $expected);
+
+                    let back = roundtripped.into_py(py);
+                    locals.set_item("back", &back).unwrap();
+                    py.run_bound(
+                        "assert py_dec.is_nan() == back.is_nan()\n\
+                         assert py_dec.is_snan() == back.is_snan()\n\
+                         assert py_dec.is_infinite() == back.is_infinite()\n\
+                         assert py_dec.as_tuple().sign == back.as_tuple().sign",
+                        None,
+                        Some(&locals),
+                    )
+                    .unwrap();
+                })
+            }
+        };
+    }
+
+    convert_pydecimal_specials!(convert_pydecimal_nan, "NaN", PyDecimal::NaN);
+    convert_pydecimal_specials!(convert_pydecimal_snan, "sNaN", PyDecimal::SignalingNaN);
+    convert_pydecimal_specials!(
+        convert_pydecimal_infinity,
+        "Infinity",
+        PyDecimal::Infinity { negative: false }
+    );
+    convert_pydecimal_specials!(
+        convert_pydecimal_neg_infinity,
+        "-Infinity",
+        PyDecimal::Infinity { negative: true }
+    );
+
+    #[test]
+    fn convert_pydecimal_finite() {
+        Python::with_gil(|py| {
+            let locals = PyDict::new_bound(py);
+            py.run_bound(
+                "import decimal\npy_dec = decimal.Decimal(\"1.25\")",
+                None,
+                Some(&locals),
+            )
+            .unwrap();
+            let py_dec = locals.get_item("py_dec").unwrap().unwrap();
+            let roundtripped: PyDecimal = py_dec.extract().unwrap();
+            assert_eq!(
+                roundtripped,
+                PyDecimal::Finite(BigDecimal::new(BigInt::from(125), 2))
+            );
+        })
+    }
+
+    #[test]
+    fn convert_pydecimal_from_int() {
+        Python::with_gil(|py| {
+            let roundtripped: PyDecimal = 5i64.into_py(py).extract(py).unwrap();
+            assert_eq!(roundtripped, PyDecimal::Finite(BigDecimal::from(5)));
+        })
+    }
+
+    #[test]
+    fn convert_pydecimal_from_big_int_beyond_i64() {
+        // big ints have neither i64 range nor an `as_tuple()`/`is_nan()` -- must not raise
+        Python::with_gil(|py| {
+            let locals = PyDict::new_bound(py);
+            py.run_bound("py_num = 10 ** 30", None, Some(&locals))
+                .unwrap();
+            let py_num = locals.get_item("py_num").unwrap().unwrap();
+            let roundtripped: PyDecimal = py_num.extract().unwrap();
+            let expected = BigDecimal::from_str(&format!("1{}", "0".repeat(30))).unwrap();
+            assert_eq!(roundtripped, PyDecimal::Finite(expected));
+        })
+    }
+
+    #[test]
+    fn test_to_object_with_context_honors_precision_and_rounding() {
+        // 1.25 at 2 significant digits is a genuine rounding tie: ROUND_HALF_EVEN rounds
+        // down to "1.2" (2 is even) while ROUND_HALF_UP rounds up to "1.3". Only a tie like
+        // this can tell apart "honors the rounding mode" from "honors the precision only".
+        Python::with_gil(|py| {
+            let num = BigDecimal::from_str("1.25").unwrap();
+            let locals = PyDict::new_bound(py);
+            py.run_bound(
+                "import decimal\n\
+                 ctx = decimal.getcontext()\n\
+                 old_prec, old_rounding = ctx.prec, ctx.rounding\n\
+                 ctx.prec = 2",
+                None,
+                Some(&locals),
+            )
+            .unwrap();
+
+            py.run_bound(
+                "ctx.rounding = decimal.ROUND_HALF_EVEN",
+                None,
+                Some(&locals),
+            )
+            .unwrap();
+            let rounded_even = num.to_object_with_context(py).unwrap();
+            locals.set_item("rounded_even", rounded_even).unwrap();
+
+            py.run_bound("ctx.rounding = decimal.ROUND_HALF_UP", None, Some(&locals))
+                .unwrap();
+            let rounded_up = num.to_object_with_context(py).unwrap();
+            locals.set_item("rounded_up", rounded_up).unwrap();
+
+            py.run_bound(
+                "assert rounded_even == decimal.Decimal(\"1.2\")\n\
+                 assert rounded_up == decimal.Decimal(\"1.3\")\n\
+                 ctx.prec, ctx.rounding = old_prec, old_rounding",
+                None,
+                Some(&locals),
+            )
+            .unwrap();
+        })
+    }
 }